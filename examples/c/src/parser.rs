@@ -559,7 +559,7 @@ impl<'a> Parser<'a> {
                     }
                 })
                 .nth(1)
-                .map_or(false, |i| self.is_type_name(self.pos + i))
+                .is_some_and(|i| self.is_type_name(self.pos + i))
         } else {
             false
         }
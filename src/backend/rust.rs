@@ -84,14 +84,13 @@ impl RustOutput {
 
         let mut token_enumerators = "{\n    EOF,\n".to_string();
         for token in file.token_decls(cst) {
-            if let Some((symbol, _)) = token.symbol(cst) {
-                if !(symbol.is_empty()
-                    || symbol.starts_with("'<") && symbol.ends_with(">'") && symbol.len() > 4)
-                {
-                    token_enumerators += "    #[token(\"";
-                    token_enumerators += &symbol[1..symbol.len() - 1];
-                    token_enumerators += "\")]\n";
-                }
+            if let Some(spelling) = token
+                .symbol(cst)
+                .and_then(|(symbol, _)| Self::fixed_spelling(symbol))
+            {
+                token_enumerators += "    #[token(\"";
+                token_enumerators += spelling;
+                token_enumerators += "\")]\n";
             }
             let (name, _) = token.name(cst).unwrap();
             token_enumerators += "    ";
@@ -279,7 +278,7 @@ impl RustOutput {
             let is_forwarding = if let Regex::Name(name) = op {
                 sema.decl_bindings
                     .get(&name.syntax())
-                    .map_or(false, |n| RuleDecl::cast(cst, *n).is_some())
+                    .is_some_and(|n| RuleDecl::cast(cst, *n).is_some())
             } else {
                 false
             };
@@ -973,6 +972,18 @@ impl RustOutput {
         Ok(())
     }
 
+    /// Returns the spelling of a token symbol, unless the symbol is empty or a description like
+    /// `'<number>'` of a token without a fixed spelling.
+    fn fixed_spelling(symbol: &str) -> Option<&str> {
+        if symbol.is_empty()
+            || symbol.starts_with("'<") && symbol.ends_with(">'") && symbol.len() > 4
+        {
+            None
+        } else {
+            Some(&symbol[1..symbol.len() - 1])
+        }
+    }
+
     fn snake_to_pascal_case(name: &str) -> String {
         let mut res = String::new();
         let mut upper = true;
@@ -1006,20 +1017,48 @@ impl RustOutput {
         }
         let mut rules = "".to_string();
         let mut rule_names = HashSet::new();
+        let mut visit_methods = "".to_string();
+        let mut visit_arms = "".to_string();
+        let mut add_rule = |rule_name: &str| {
+            let kind = Self::snake_to_pascal_case(rule_name);
+            rules += "\n    ";
+            rules += &kind;
+            rules += ",";
+            visit_methods += &format!(
+                "\n    fn visit_{rule_name}(&mut self, cst: &Cst, node: NodeRef) {{\
+                 \n        self.walk(cst, node);\
+                 \n    }}"
+            );
+            visit_arms += &format!(
+                "\n            Node::Rule(Rule::{kind}, _) => self.visit_{rule_name}(cst, node),"
+            );
+        };
         for rule in file.rule_decls(cst) {
             let rule_name = rule.name(cst).unwrap().0;
             rule_names.insert(rule_name);
-            rules += "\n    ";
-            rules += &Self::snake_to_pascal_case(rule_name);
-            rules += ",";
+            add_rule(rule_name);
         }
         for rule_name in sema.rule_bindings.iter() {
             if rule_names.contains(rule_name) {
                 continue;
             }
-            rules += "\n    ";
-            rules += &Self::snake_to_pascal_case(rule_name);
-            rules += ",";
+            add_rule(rule_name);
+        }
+        let mut token_list = "".to_string();
+        let mut token_names = "".to_string();
+        let mut token_spellings = "".to_string();
+        for token in file.token_decls(cst) {
+            let name = token.name(cst).unwrap().0;
+            token_list += " Token::";
+            token_list += name;
+            token_list += ",";
+            token_names += &format!("\n            Token::{name} => \"{name}\",");
+            if let Some(spelling) = token
+                .symbol(cst)
+                .and_then(|(symbol, _)| Self::fixed_spelling(symbol))
+            {
+                token_spellings += &format!("\n            Token::{name} => Some(\"{spelling}\"),");
+            }
         }
         let mut skip = "".to_string();
         for token in sema.skipped.iter() {
@@ -1032,7 +1071,12 @@ impl RustOutput {
                 include_str!("../skeleton/generated.rs"),
                 rules,
                 skip,
-                sema.start.unwrap().name(cst).unwrap().0
+                sema.start.unwrap().name(cst).unwrap().0,
+                token_list,
+                token_names,
+                token_spellings,
+                visit_methods,
+                visit_arms,
             )
             .as_bytes(),
         )?;
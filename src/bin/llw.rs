@@ -1,6 +1,27 @@
 #![cfg(feature = "cli")]
 
 use clap::{arg, crate_name, crate_version, error::ErrorKind, ArgAction, Command};
+use codespan_reporting::diagnostic::Severity;
+use std::collections::HashMap;
+
+fn parse_override(value: &str) -> Result<(&str, Option<Severity>), String> {
+    let (code, level) = value
+        .split_once('=')
+        .ok_or_else(|| format!("invalid severity override `{value}`, expected CODE=LEVEL"))?;
+    let severity = match level {
+        "error" => Some(Severity::Error),
+        "warning" => Some(Severity::Warning),
+        "note" => Some(Severity::Note),
+        "help" => Some(Severity::Help),
+        "allow" => None,
+        _ => {
+            return Err(format!(
+                "invalid severity `{level}`, expected one of: error, warning, note, help, allow"
+            ))
+        }
+    };
+    Ok((code, severity))
+}
 
 fn main() {
     let mut cmd = Command::new(crate_name!())
@@ -11,6 +32,11 @@ fn main() {
         .arg(arg!(-g --graph "Output a graphviz file for the grammar"))
         .arg(arg!(-s --short "Use short diagnostics"))
         .arg(arg!(-v --verbose "Sets the level of verbosity").action(ArgAction::Count))
+        .arg(
+            arg!(-W --severity <OVERRIDE> "Overrides the severity of a diagnostic code (e.g. W001=error or W002=allow)")
+                .action(ArgAction::Append)
+                .required(false),
+        )
         .arg(
             arg!(-o --output <FILE> "Sets the output directory")
                 .default_value(".")
@@ -23,6 +49,15 @@ fn main() {
 
     let input = matches.get_one::<String>("INPUT").unwrap();
     let output = matches.get_one::<String>("output").unwrap();
+    let overrides = match matches
+        .get_many::<String>("severity")
+        .unwrap_or_default()
+        .map(|value| parse_override(value))
+        .collect::<Result<HashMap<_, _>, _>>()
+    {
+        Ok(overrides) => overrides,
+        Err(e) => cmd.error(ErrorKind::InvalidValue, e).exit(),
+    };
     match lelwel::compile(
         input,
         output,
@@ -30,6 +65,7 @@ fn main() {
         matches.get_count("verbose"),
         matches.get_flag("graph"),
         matches.get_flag("short"),
+        &overrides,
     ) {
         Ok(success) => std::process::exit(if success { 0 } else { 1 }),
         Err(e) => cmd.error(ErrorKind::InvalidValue, format!("{}", e)).exit(),
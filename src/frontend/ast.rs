@@ -122,18 +122,16 @@ impl Cst<'_> {
     fn child_node_iter<T: AstNode>(
         &self,
         syntax: NodeRef,
-    ) -> std::iter::FilterMap<CstChildren, impl FnMut(NodeRef) -> Option<T> + '_> {
+    ) -> std::iter::FilterMap<CstChildren<'_>, impl FnMut(NodeRef) -> Option<T> + '_> {
         self.children(syntax).filter_map(|c| T::cast(self, c))
     }
-    fn child_token(&self, syntax: NodeRef, token: Token) -> Option<(&str, Span)> {
-        self.children(syntax).find_map(|c| self.get_token(c, token))
-    }
 }
 
 pub trait Named: AstNode {
     fn name<'a>(&self, cst: &'a Cst) -> Option<(&'a str, Span)>;
 }
 impl File {
+    #[allow(clippy::type_complexity)]
     pub fn token_decls<'a>(
         &self,
         cst: &'a Cst,
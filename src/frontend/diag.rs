@@ -1,4 +1,4 @@
-use codespan_reporting::diagnostic::Label;
+use codespan_reporting::diagnostic::{Label, LabelStyle, Severity};
 
 use super::parser::{Diagnostic, Span};
 
@@ -24,6 +24,7 @@ pub const REDEFINE_AS_RIGHT: &str = "E019";
 
 pub const UNUSED_RULE: &str = "W001";
 pub const UNUSED_TOKEN: &str = "W002";
+pub const NON_PRODUCTIVE_RULE: &str = "W003";
 
 pub trait LanguageErrors<'a> {
     fn invalid_binding_pos(span: &Span) -> Self;
@@ -38,6 +39,7 @@ pub trait LanguageErrors<'a> {
     fn predefined_token_name(span: &Span) -> Self;
     fn unused_rule(span: &Span) -> Self;
     fn unused_token(span: &Span) -> Self;
+    fn non_productive_rule(span: &Span) -> Self;
     fn ll1_conflict_alt(span: &Span, conflicting: Vec<(Span, String)>) -> Self;
     fn ll1_conflict_left_rec(span: &Span, conflicting: Vec<(Span, String)>) -> Self;
     fn ll1_conflict_rep(span: &Span, conflicting: String) -> Self;
@@ -260,4 +262,152 @@ impl<'a> LanguageErrors<'a> for Diagnostic {
             .with_message("unused token")
             .with_labels(vec![Label::primary((), span.clone())])
     }
+
+    fn non_productive_rule(span: &Span) -> Self {
+        Diagnostic::warning()
+            .with_code(NON_PRODUCTIVE_RULE)
+            .with_message("non-productive rule")
+            .with_labels(vec![Label::primary((), span.clone())])
+            .with_notes(vec![
+                "note: the rule cannot derive a finite sequence of tokens".to_string(),
+            ])
+    }
+}
+
+/// Renders a diagnostic as plain text without any color codes.
+///
+/// The first line uses the format `file:line:col: severity[code]: message`. If the diagnostic
+/// has a primary label, it is followed by the corresponding source line with the labeled range
+/// underlined by carets and the label message. Each secondary label is rendered on its own line
+/// in the format `file:line:col: message`. Notes are appended on separate lines.
+///
+/// Label offsets that are out of bounds or not on a character boundary are moved back to the
+/// previous character boundary.
+pub fn render_diagnostic_plain(name: &str, source: &str, diag: &Diagnostic) -> String {
+    fn char_boundary(source: &str, offset: usize) -> usize {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+    fn location(source: &str, offset: usize) -> (usize, usize, usize) {
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_number = source[..offset].matches('\n').count() + 1;
+        let column_number = source[line_start..offset].chars().count() + 1;
+        (line_start, line_number, column_number)
+    }
+
+    let severity = match diag.severity {
+        Severity::Bug => "bug",
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+        Severity::Help => "help",
+    };
+    let code = diag
+        .code
+        .as_ref()
+        .map_or(String::new(), |code| format!("[{code}]"));
+    let primary = diag
+        .labels
+        .iter()
+        .find(|label| label.style == LabelStyle::Primary);
+
+    let mut output = String::new();
+    if let Some(label) = primary {
+        let start = char_boundary(source, label.range.start);
+        let end = char_boundary(source, label.range.end.max(start));
+        let (line_start, line_number, column_number) = location(source, start);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let line = source[line_start..line_end].trim_end_matches('\r');
+
+        // keep tabs in the indentation so the carets line up with the source line
+        let indent = source[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let carets = source[start..end.min(line_end)].chars().count().max(1);
+
+        output.push_str(&format!(
+            "{name}:{line_number}:{column_number}: {severity}{code}: {}\n",
+            diag.message
+        ));
+        output.push_str(&format!("{line}\n{indent}{}", "^".repeat(carets)));
+        if !label.message.is_empty() {
+            output.push_str(&format!(" {}", label.message));
+        }
+        output.push('\n');
+    } else {
+        output.push_str(&format!("{severity}{code}: {}\n", diag.message));
+    }
+    for label in diag
+        .labels
+        .iter()
+        .filter(|label| label.style == LabelStyle::Secondary)
+    {
+        let (_, line_number, column_number) =
+            location(source, char_boundary(source, label.range.start));
+        output.push_str(&format!("{name}:{line_number}:{column_number}:"));
+        if !label.message.is_empty() {
+            output.push_str(&format!(" {}", label.message));
+        }
+        output.push('\n');
+    }
+    for note in diag.notes.iter() {
+        output.push_str(&format!("= {note}\n"));
+    }
+    output
+}
+
+/// Merges two lists of diagnostics into a single list sorted by the start of the primary label.
+///
+/// The sort is stable and diagnostics without a primary label are placed at the end. Identical
+/// diagnostics are only kept once.
+pub fn merge_diagnostics(a: Vec<Diagnostic>, b: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    fn position(diag: &Diagnostic) -> usize {
+        diag.labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Primary)
+            .map_or(usize::MAX, |label| label.range.start)
+    }
+    let mut diags = a;
+    diags.extend(b);
+    diags.sort_by_key(position);
+
+    let mut merged: Vec<Diagnostic> = Vec::with_capacity(diags.len());
+    for diag in diags {
+        let pos = position(&diag);
+        if !merged
+            .iter()
+            .rev()
+            .take_while(|other| position(other) == pos)
+            .any(|other| *other == diag)
+        {
+            merged.push(diag);
+        }
+    }
+    merged
+}
+
+/// Changes the severity of diagnostics based on their code.
+///
+/// A code that maps to `None` removes the corresponding diagnostics. Diagnostics without a code
+/// or with a code that is not in the map are left unchanged.
+pub fn apply_severity_overrides(
+    diags: &mut Vec<Diagnostic>,
+    overrides: &std::collections::HashMap<&str, Option<Severity>>,
+) {
+    diags.retain_mut(
+        |diag| match diag.code.as_deref().and_then(|code| overrides.get(code)) {
+            Some(Some(severity)) => {
+                diag.severity = *severity;
+                true
+            }
+            Some(None) => false,
+            None => true,
+        },
+    );
 }
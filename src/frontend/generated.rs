@@ -135,7 +135,7 @@ impl<'a> Cst<'a> {
     fn mark(&self) -> MarkClosed {
         MarkClosed(self.nodes.len() as CstIndex)
     }
-    pub fn children(&self, node: NodeRef) -> CstChildren {
+    pub fn children(&self, node: NodeRef) -> CstChildren<'_> {
         let iter = if let Some(Node::Rule(_, end_offset)) = self.nodes.get(node.0 as usize) {
             self.nodes[node.0 as usize + 1..node.0 as usize + *end_offset as usize + 1].iter()
         } else {
@@ -159,10 +159,7 @@ impl<'a> Cst<'a> {
                 .children(node)
                 .filter(|node_ref| {
                     if let Node::Token(idx) = self.get(*node_ref) {
-                        !matches!(
-                            self.tokens[idx as usize],
-                            Token::Error | Token::Comment | Token::DocComment | Token::Whitespace
-                        )
+                        !Parser::is_skipped(self.tokens[idx as usize])
                     } else {
                         true
                     }
@@ -197,6 +194,122 @@ impl<'a> Cst<'a> {
             None
         }
     }
+    /// Returns the child rule nodes of the given kind.
+    pub fn child_rules(&self, node: NodeRef, rule: Rule) -> impl Iterator<Item = NodeRef> + '_ {
+        self.children(node)
+            .filter_map(move |c| self.get_rule(c, rule))
+    }
+    /// Returns the first child rule node of the given kind.
+    pub fn child_rule(&self, node: NodeRef, rule: Rule) -> Option<NodeRef> {
+        self.child_rules(node, rule).next()
+    }
+    /// Returns the child tokens of the given kind.
+    pub fn child_tokens(
+        &self,
+        node: NodeRef,
+        token: Token,
+    ) -> impl Iterator<Item = (&'a str, Span)> + '_ {
+        self.children(node)
+            .filter_map(move |c| self.get_token(c, token))
+    }
+    /// Returns the first child token of the given kind.
+    pub fn child_token(&self, node: NodeRef, token: Token) -> Option<(&'a str, Span)> {
+        self.child_tokens(node, token).next()
+    }
+    /// Returns the rule node that directly contains the node.
+    pub fn parent(&self, node: NodeRef) -> Option<NodeRef> {
+        (0..node.0)
+            .rev()
+            .find(|i| {
+                matches!(
+                    self.nodes[*i as usize],
+                    Node::Rule(_, end_offset) if i + end_offset >= node.0
+                )
+            })
+            .map(NodeRef)
+    }
+    /// Returns the rule nodes that contain the node, starting with its parent.
+    pub fn ancestors(&self, node: NodeRef) -> impl Iterator<Item = NodeRef> + '_ {
+        std::iter::successors(self.parent(node), |node| self.parent(*node))
+    }
+    /// Returns the token node whose range contains the byte offset.
+    ///
+    /// As ranges exclude their end, an offset between two adjacent tokens yields the second one.
+    pub fn token_at(&self, offset: usize) -> Option<NodeRef> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .find_map(|(i, node)| match node {
+                Node::Token(idx) => {
+                    let range = &self.ranges[*idx as usize];
+                    (range.start as usize <= offset && offset < range.end as usize)
+                        .then_some(NodeRef(i as CstIndex))
+                }
+                Node::Rule(..) => None,
+            })
+    }
+    /// Returns the narrowest rule node whose span contains the byte offset.
+    ///
+    /// Spans exclude skipped tokens, so for an offset inside a skipped token this is not
+    /// necessarily the parent of the node returned by `token_at`, but the narrowest rule that
+    /// surrounds the skipped token.
+    pub fn node_at(&self, offset: usize) -> Option<NodeRef> {
+        let contains = |node: NodeRef| {
+            matches!(self.get(node), Node::Rule(..))
+                && self
+                    .get_span(node)
+                    .is_some_and(|span| span.start <= offset && offset < span.end)
+        };
+        let mut node = Some(NodeRef::ROOT).filter(|node| contains(*node))?;
+        while let Some(child) = self.children(node).find(|child| contains(*child)) {
+            node = child;
+        }
+        Some(node)
+    }
+    /// Returns the skipped tokens after a token node that start on the same line.
+    ///
+    /// Skipped tokens are the tokens of the `skip` declaration as well as `Token::Error`, which
+    /// is produced for invalid input by the lexer.
+    pub fn trailing_trivia(&self, node: NodeRef) -> Vec<(Token, Span)> {
+        let Some(Node::Token(idx)) = self.nodes.get(node.0 as usize) else {
+            return vec![];
+        };
+        let trivia = |i: usize| {
+            let range = &self.ranges[i];
+            Parser::is_skipped(self.tokens[i])
+                .then(|| (self.tokens[i], range.start as usize..range.end as usize))
+        };
+        let end = self.ranges[*idx as usize].end as usize;
+        (*idx as usize + 1..self.tokens.len())
+            .map_while(trivia)
+            .take_while(|(_, span)| !self.source[end..span.start].contains('\n'))
+            .collect()
+    }
+    /// Returns the skipped tokens before a token node that are not trailing trivia of the
+    /// previous token.
+    ///
+    /// As for `trailing_trivia`, lexer error tokens are included.
+    pub fn leading_trivia(&self, node: NodeRef) -> Vec<(Token, Span)> {
+        let Some(Node::Token(idx)) = self.nodes.get(node.0 as usize) else {
+            return vec![];
+        };
+        let trivia = |i: usize| {
+            let range = &self.ranges[i];
+            Parser::is_skipped(self.tokens[i])
+                .then(|| (self.tokens[i], range.start as usize..range.end as usize))
+        };
+        let mut trivia = (0..*idx as usize)
+            .rev()
+            .map_while(trivia)
+            .collect::<Vec<_>>();
+        trivia.reverse();
+        let first = *idx as usize - trivia.len();
+        if first > 0 {
+            let end = self.ranges[first - 1].end as usize;
+            trivia.retain(|(_, span)| self.source[end..span.start].contains('\n'));
+        }
+        trivia
+    }
 }
 
 #[allow(clippy::unnecessary_cast)]
@@ -238,6 +351,178 @@ impl std::fmt::Display for Cst<'_> {
     }
 }
 
+impl Token {
+    /// Returns all tokens declared in the grammar including `EOF`.
+    #[allow(dead_code)]
+    pub fn all() -> &'static [Token] {
+        &[
+            Token::EOF,
+            Token::Token,
+            Token::Start,
+            Token::Right,
+            Token::Skip,
+            Token::Colon,
+            Token::Semi,
+            Token::Equal,
+            Token::LPar,
+            Token::RPar,
+            Token::LBrak,
+            Token::RBrak,
+            Token::Or,
+            Token::Star,
+            Token::Plus,
+            Token::Id,
+            Token::Str,
+            Token::Predicate,
+            Token::Action,
+            Token::Binding,
+            Token::OpenNode,
+            Token::CloseNode,
+            Token::Comment,
+            Token::DocComment,
+            Token::Whitespace,
+        ]
+    }
+    /// Returns the name of the token as declared in the grammar.
+    #[allow(dead_code, unreachable_patterns)]
+    pub fn name(self) -> &'static str {
+        match self {
+            Token::EOF => "EOF",
+            Token::Token => "Token",
+            Token::Start => "Start",
+            Token::Right => "Right",
+            Token::Skip => "Skip",
+            Token::Colon => "Colon",
+            Token::Semi => "Semi",
+            Token::Equal => "Equal",
+            Token::LPar => "LPar",
+            Token::RPar => "RPar",
+            Token::LBrak => "LBrak",
+            Token::RBrak => "RBrak",
+            Token::Or => "Or",
+            Token::Star => "Star",
+            Token::Plus => "Plus",
+            Token::Id => "Id",
+            Token::Str => "Str",
+            Token::Predicate => "Predicate",
+            Token::Action => "Action",
+            Token::Binding => "Binding",
+            Token::OpenNode => "OpenNode",
+            Token::CloseNode => "CloseNode",
+            Token::Comment => "Comment",
+            Token::DocComment => "DocComment",
+            Token::Whitespace => "Whitespace",
+            Token::Error => "Error",
+            _ => "<unknown>",
+        }
+    }
+    /// Returns the fixed text of the token, if it has one.
+    #[allow(dead_code, unreachable_patterns)]
+    pub fn spelling(self) -> Option<&'static str> {
+        match self {
+            Token::Token => Some("token"),
+            Token::Start => Some("start"),
+            Token::Right => Some("right"),
+            Token::Skip => Some("skip"),
+            Token::Colon => Some(":"),
+            Token::Semi => Some(";"),
+            Token::Equal => Some("="),
+            Token::LPar => Some("("),
+            Token::RPar => Some(")"),
+            Token::LBrak => Some("["),
+            Token::RBrak => Some("]"),
+            Token::Or => Some("|"),
+            Token::Star => Some("*"),
+            Token::Plus => Some("+"),
+            _ => None,
+        }
+    }
+}
+
+/// Traverses the CST in source order.
+///
+/// Each `visit_*` method walks the children of its node by default, so implementations only
+/// override the methods for the nodes they are interested in. Tokens are passed to `token`,
+/// which is kept out of the `visit_*` namespace so it cannot clash with a rule or binding.
+#[allow(clippy::unnecessary_cast, dead_code, unused_variables)]
+pub trait Visitor {
+    fn visit(&mut self, cst: &Cst, node: NodeRef) {
+        match cst.get(node) {
+            Node::Rule(Rule::Error, _) => self.visit_error(cst, node),
+            Node::Rule(Rule::File, _) => self.visit_file(cst, node),
+            Node::Rule(Rule::Decl, _) => self.visit_decl(cst, node),
+            Node::Rule(Rule::StartDecl, _) => self.visit_start_decl(cst, node),
+            Node::Rule(Rule::RightDecl, _) => self.visit_right_decl(cst, node),
+            Node::Rule(Rule::SkipDecl, _) => self.visit_skip_decl(cst, node),
+            Node::Rule(Rule::TokenList, _) => self.visit_token_list(cst, node),
+            Node::Rule(Rule::TokenDecl, _) => self.visit_token_decl(cst, node),
+            Node::Rule(Rule::RuleDecl, _) => self.visit_rule_decl(cst, node),
+            Node::Rule(Rule::Regex, _) => self.visit_regex(cst, node),
+            Node::Rule(Rule::Alternation, _) => self.visit_alternation(cst, node),
+            Node::Rule(Rule::Concat, _) => self.visit_concat(cst, node),
+            Node::Rule(Rule::Postfix, _) => self.visit_postfix(cst, node),
+            Node::Rule(Rule::Paren, _) => self.visit_paren(cst, node),
+            Node::Rule(Rule::Optional, _) => self.visit_optional(cst, node),
+            Node::Rule(Rule::Atomic, _) => self.visit_atomic(cst, node),
+            Node::Token(idx) => self.token(cst, node, cst.tokens[idx as usize]),
+        }
+    }
+    fn walk(&mut self, cst: &Cst, node: NodeRef) {
+        for child in cst.children(node) {
+            self.visit(cst, child);
+        }
+    }
+    fn token(&mut self, cst: &Cst, node: NodeRef, token: Token) {}
+    fn visit_error(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_file(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_start_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_right_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_skip_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_token_list(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_token_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_rule_decl(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_regex(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_alternation(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_concat(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_postfix(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_paren(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_optional(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+    fn visit_atomic(&mut self, cst: &Cst, node: NodeRef) {
+        self.walk(cst, node);
+    }
+}
+
 macro_rules! expect {
     ($tok:ident, $sym:literal, $self:expr, $diags:expr) => {
         if let Token::$tok = $self.current {
@@ -0,0 +1,95 @@
+use logos::Logos;
+
+use super::parser::{tokenize, Cst, NodeRef, Span, Token, Visitor};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HighlightCategory {
+    Keyword,
+    String,
+    Number,
+    Comment,
+    Operator,
+    Macro,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightSpan {
+    pub byte_range: Span,
+    pub category: HighlightCategory,
+}
+
+/// Maps a token kind to the category used for syntax highlighting.
+///
+/// Tokens without a category (e.g. whitespace) are not highlighted.
+pub trait Highlight {
+    fn category(&self) -> Option<HighlightCategory>;
+}
+
+impl Highlight for Token {
+    fn category(&self) -> Option<HighlightCategory> {
+        match self {
+            Token::Token | Token::Start | Token::Right | Token::Skip => {
+                Some(HighlightCategory::Keyword)
+            }
+            Token::Comment | Token::DocComment => Some(HighlightCategory::Comment),
+            Token::Str => Some(HighlightCategory::String),
+            Token::Colon
+            | Token::Semi
+            | Token::Equal
+            | Token::LPar
+            | Token::RPar
+            | Token::LBrak
+            | Token::RBrak
+            | Token::Or
+            | Token::Star
+            | Token::Plus => Some(HighlightCategory::Operator),
+            Token::Predicate
+            | Token::Action
+            | Token::Binding
+            | Token::OpenNode
+            | Token::CloseNode => Some(HighlightCategory::Macro),
+            Token::EOF | Token::Whitespace | Token::Id | Token::Error => None,
+        }
+    }
+}
+
+/// Calculates the highlight spans for a token stream as returned by `tokenize`.
+///
+/// Skipped tokens such as comments are part of the stream, so they are highlighted as well.
+pub fn highlight_tokens<T: Highlight>(tokens: &[T], ranges: &[Span]) -> Vec<HighlightSpan> {
+    tokens
+        .iter()
+        .zip(ranges)
+        .filter_map(|(token, range)| {
+            token.category().map(|category| HighlightSpan {
+                byte_range: range.clone(),
+                category,
+            })
+        })
+        .collect()
+}
+
+/// Calculates the highlight spans for a lelwel grammar file.
+pub fn highlight(source: &str) -> Vec<HighlightSpan> {
+    let mut diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut diags);
+    highlight_tokens(&tokens, &ranges)
+}
+
+/// Calculates the highlight spans for the tokens of a parsed lelwel grammar file.
+pub fn highlight_cst(cst: &Cst) -> Vec<HighlightSpan> {
+    struct Highlighter(Vec<HighlightSpan>);
+    impl Visitor for Highlighter {
+        fn token(&mut self, cst: &Cst, node: NodeRef, token: Token) {
+            if let (Some(category), Some(byte_range)) = (token.category(), cst.get_span(node)) {
+                self.0.push(HighlightSpan {
+                    byte_range,
+                    category,
+                });
+            }
+        }
+    }
+    let mut highlighter = Highlighter(vec![]);
+    highlighter.visit(cst, NodeRef::ROOT);
+    highlighter.0
+}
@@ -1,5 +1,6 @@
 pub mod ast;
 pub mod diag;
+pub mod highlight;
 pub mod parser;
 pub mod printer;
 pub mod sema;
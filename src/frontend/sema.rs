@@ -43,6 +43,7 @@ impl SemanticPass {
             LL1Validator::run(cst, diags, &mut sema);
             UsageValidator::run(cst, diags, &mut sema);
             if !diags.iter().any(|d| d.severity == Severity::Error) {
+                ProductivityValidator::run(cst, diags, &sema);
                 RecoverySetGenerator::new().run(cst, &mut sema);
             }
         }
@@ -433,7 +434,7 @@ impl<'a> GeneralCheck<'a> {
                 Regex::Symbol(_) => true,
                 Regex::Paren(op) => op
                     .inner(cst)
-                    .map_or(false, |op| is_operator(sema, cst, &op, outer)),
+                    .is_some_and(|op| is_operator(sema, cst, &op, outer)),
                 Regex::Alternation(op) if outer => {
                     let mut res = true;
                     op.operands(cst).for_each(|n| {
@@ -520,16 +521,15 @@ impl<'a> GeneralCheck<'a> {
                             }
                         }
                     }
-                    Regex::Name(ref name) => {
+                    Regex::Name(ref name)
                         if sema
                             .decl_bindings
                             .get(&name.syntax())
                             .and_then(|n| RuleDecl::cast(cst, *n))
-                            .is_some()
-                        {
-                            // exit branch
-                            branches.push(op)
-                        }
+                            .is_some() =>
+                    {
+                        // exit branch
+                        branches.push(op)
                     }
                     _ => {}
                 }
@@ -553,7 +553,7 @@ impl<'a> GeneralCheck<'a> {
             match regex {
                 Regex::Paren(paren) => paren
                     .inner(cst)
-                    .map_or(false, |inner| is_rule_ref(sema, cst, inner)),
+                    .is_some_and(|inner| is_rule_ref(sema, cst, inner)),
                 Regex::Alternation(alt) => {
                     for op in alt.operands(cst) {
                         if !is_rule_ref(sema, cst, op) {
@@ -566,7 +566,7 @@ impl<'a> GeneralCheck<'a> {
                     let mut it = concat.operands(cst);
                     if !it
                         .next()
-                        .map_or(false, |op| matches!(op, Regex::Predicate(_)))
+                        .is_some_and(|op| matches!(op, Regex::Predicate(_)))
                     {
                         return false;
                     }
@@ -586,7 +586,7 @@ impl<'a> GeneralCheck<'a> {
             match regex {
                 Regex::Concat(concat) => {
                     let mut it = concat.operands(cst);
-                    if !it.next().map_or(false, |op| is_rule_ref(sema, cst, op)) {
+                    if !it.next().is_some_and(|op| is_rule_ref(sema, cst, op)) {
                         return false;
                     }
                     for op in it {
@@ -698,7 +698,7 @@ impl<'a> LL1Validator {
                         let op_first = sema.first_sets[&op.syntax()].clone();
                         use_next = op_first.contains(&TokenName("ɛ"));
                         let first = sema.first_sets.get_mut(&regex.syntax()).unwrap();
-                        first.extend(op_first.into_iter());
+                        first.extend(op_first);
                         first.remove(&TokenName("ɛ"));
                     }
                 }
@@ -716,7 +716,7 @@ impl<'a> LL1Validator {
                     sema.first_sets
                         .get_mut(&regex.syntax())
                         .unwrap()
-                        .extend(op_first.into_iter());
+                        .extend(op_first);
                 }
             }
             Regex::Star(star) => {
@@ -925,7 +925,7 @@ impl<'a> LL1Validator {
             }
             Regex::Paren(paren) => paren
                 .inner(cst)
-                .map_or(false, |inner| Self::has_predicate(cst, inner)),
+                .is_some_and(|inner| Self::has_predicate(cst, inner)),
             _ => false,
         }
     }
@@ -1079,14 +1079,13 @@ impl<'a> LL1Validator {
                     Self::check_regex(cst, sema, diags, op, rule, &[]);
                 }
             }
-            Regex::Name(name) => {
+            Regex::Name(name)
                 if sema
                     .first_sets
                     .get(&name.syntax())
-                    .map_or(false, |first| first.is_empty())
-                {
-                    diags.push(Diagnostic::consume_tokens(&regex.span(cst)));
-                }
+                    .is_some_and(|first| first.is_empty()) =>
+            {
+                diags.push(Diagnostic::consume_tokens(&regex.span(cst)));
             }
             Regex::Concat(concat) => {
                 for op in concat.operands(cst) {
@@ -1185,6 +1184,67 @@ impl UsageValidator {
     }
 }
 
+struct ProductivityValidator;
+
+impl ProductivityValidator {
+    fn run(cst: &Cst, diags: &mut Vec<Diagnostic>, sema: &SemanticData) {
+        if let Some(file) = File::cast(cst, NodeRef::ROOT) {
+            let mut productive = HashSet::new();
+            let mut change = true;
+            while change {
+                let count = productive.len();
+                for rule in file.rule_decls(cst) {
+                    if rule
+                        .regex(cst)
+                        .is_none_or(|regex| Self::is_productive(cst, sema, &productive, regex))
+                    {
+                        productive.insert(rule.syntax());
+                    }
+                }
+                change = count != productive.len();
+            }
+            // unused rules are already reported by the usage validator
+            for rule in file.rule_decls(cst) {
+                if sema.used.contains(&rule.syntax()) && !productive.contains(&rule.syntax()) {
+                    diags.push(Diagnostic::non_productive_rule(&rule.span(cst)));
+                }
+            }
+        }
+    }
+    fn is_productive(
+        cst: &Cst,
+        sema: &SemanticData,
+        productive: &HashSet<NodeRef>,
+        regex: Regex,
+    ) -> bool {
+        match regex {
+            Regex::Alternation(alt) => alt
+                .operands(cst)
+                .any(|op| Self::is_productive(cst, sema, productive, op)),
+            Regex::Concat(concat) => concat
+                .operands(cst)
+                .all(|op| Self::is_productive(cst, sema, productive, op)),
+            Regex::Paren(paren) => paren
+                .inner(cst)
+                .is_none_or(|inner| Self::is_productive(cst, sema, productive, inner)),
+            Regex::Plus(plus) => plus
+                .operand(cst)
+                .is_none_or(|op| Self::is_productive(cst, sema, productive, op)),
+            Regex::Name(name) => sema.decl_bindings.get(&name.syntax()).is_none_or(|node| {
+                RuleDecl::cast(cst, *node).is_none() || productive.contains(node)
+            }),
+            Regex::Optional(_)
+            | Regex::Star(_)
+            | Regex::Symbol(_)
+            | Regex::Predicate(_)
+            | Regex::Action(_)
+            | Regex::Binding(_)
+            | Regex::OpenNode(_)
+            | Regex::CloseNode(_) => true,
+        }
+    }
+}
+
 #[derive(Default)]
 struct RecoverySetGenerator {
     dom: HashMap<Regex, HashSet<Regex>>,
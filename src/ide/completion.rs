@@ -111,7 +111,7 @@ pub fn completion(cst: &Cst, pos: usize) -> Option<CompletionResponse> {
     let mut items = vec![];
 
     let file = File::cast(cst, NodeRef::ROOT)?;
-    if let Some(node) = lookup_node(cst, NodeRef::ROOT, pos) {
+    if let Some(node) = lookup_node(cst, pos) {
         match cst.get(node) {
             Node::Rule(
                 Rule::Alternation
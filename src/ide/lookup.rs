@@ -8,14 +8,8 @@ fn contains(span: &Span, pos: usize) -> bool {
     span.start <= pos && pos < span.end
 }
 
-pub fn lookup_node(cst: &Cst, node: NodeRef, pos: usize) -> Option<NodeRef> {
-    cst.children(node)
-        .filter(|node| matches!(cst.get(*node), Node::Rule(..)))
-        .find(|node| {
-            cst.get_span(*node)
-                .map_or(false, |span| contains(&span, pos))
-        })
-        .and_then(|node| lookup_node(cst, node, pos).or(Some(node)))
+pub fn lookup_node(cst: &Cst, pos: usize) -> Option<NodeRef> {
+    cst.node_at(pos).filter(|node| *node != NodeRef::ROOT)
 }
 pub fn find_node<P: Fn(Rule) -> bool>(
     cst: &Cst,
@@ -31,10 +25,7 @@ pub fn find_node<P: Fn(Rule) -> bool>(
                 false
             }
         })
-        .find(|node| {
-            cst.get_span(*node)
-                .map_or(false, |span| contains(&span, pos))
-        })
+        .find(|node| cst.get_span(*node).is_some_and(|span| contains(&span, pos)))
         .and_then(|node| find_node(cst, node, pos, pred).or(Some(node)))
 }
 
@@ -46,7 +37,7 @@ pub fn lookup_definition(
     file: &SimpleFile<&str, &str>,
     parser_path: &std::path::Path,
 ) -> Option<Location> {
-    lookup_node(cst, NodeRef::ROOT, pos).and_then(|node| {
+    lookup_node(cst, pos).and_then(|node| {
         if let Some((rule_name, number)) = sema.predicates.get(&node) {
             lookup_parser_impl_definition("predicate", rule_name, number, parser_path)
         } else if let Some((rule_name, number)) = sema.actions.get(&node) {
@@ -69,7 +60,7 @@ pub fn lookup_references(
     pos: usize,
     with_def: bool,
 ) -> Vec<NodeRef> {
-    if let Some(def) = lookup_node(cst, NodeRef::ROOT, pos) {
+    if let Some(def) = lookup_node(cst, pos) {
         let mut refs = sema
             .decl_bindings
             .iter()
@@ -16,6 +16,9 @@ use self::lookup::*;
 mod completion;
 mod hover;
 mod lookup;
+mod semantic_tokens;
+
+pub use self::semantic_tokens::{legend as semantic_tokens_legend, semantic_tokens};
 
 struct Analyzer {
     handle: JoinHandle<()>,
@@ -113,6 +116,16 @@ impl Cache {
             None
         }
     }
+    pub async fn semantic_tokens(&mut self, uri: &Url) -> Vec<SemanticToken> {
+        let analyzer = self.analyzers.get_mut(uri).unwrap();
+        assert!(!analyzer.handle.is_finished());
+        analyzer.req_tx.send(Request::SemanticTokens).await.unwrap();
+        if let Some(Notification::SemanticTokens(tokens)) = analyzer.noti_rx.recv().await {
+            tokens
+        } else {
+            vec![]
+        }
+    }
 }
 
 enum Request {
@@ -121,6 +134,7 @@ enum Request {
     GotoDefinition(Position),
     References(Position, bool),
     Completion(CompletionParams),
+    SemanticTokens,
 }
 
 enum Notification {
@@ -129,6 +143,7 @@ enum Notification {
     GotoDefinition(Option<Location>),
     References(Vec<Location>),
     Completion(Option<CompletionResponse>),
+    SemanticTokens(Vec<SemanticToken>),
 }
 
 async fn analyze(
@@ -192,6 +207,12 @@ async fn analyze(
                     .await
                     .unwrap();
             }
+            Request::SemanticTokens => {
+                let tokens = semantic_tokens(&file, &cst);
+                noti.send(Notification::SemanticTokens(tokens))
+                    .await
+                    .unwrap();
+            }
         }
     }
 }
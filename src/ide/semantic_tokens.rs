@@ -0,0 +1,60 @@
+use codespan_reporting::files::SimpleFile;
+use tower_lsp::lsp_types::*;
+
+use crate::frontend::highlight::{highlight_cst, HighlightCategory};
+use crate::Cst;
+
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: vec![
+            SemanticTokenType::KEYWORD,
+            SemanticTokenType::STRING,
+            SemanticTokenType::NUMBER,
+            SemanticTokenType::COMMENT,
+            SemanticTokenType::OPERATOR,
+            SemanticTokenType::MACRO,
+        ],
+        token_modifiers: vec![],
+    }
+}
+
+fn token_type(category: HighlightCategory) -> u32 {
+    match category {
+        HighlightCategory::Keyword => 0,
+        HighlightCategory::String => 1,
+        HighlightCategory::Number => 2,
+        HighlightCategory::Comment => 3,
+        HighlightCategory::Operator => 4,
+        HighlightCategory::Macro => 5,
+    }
+}
+
+pub fn semantic_tokens(file: &SimpleFile<&str, &str>, cst: &Cst) -> Vec<SemanticToken> {
+    let source = file.source();
+    let mut tokens = vec![];
+    let mut prev = Position::default();
+    for span in highlight_cst(cst) {
+        // multiline tokens (e.g. comments including the newline) are split into one token per line
+        let mut start = span.byte_range.start;
+        for line in source[span.byte_range.clone()].split_inclusive('\n') {
+            let end = start + line.trim_end_matches(['\n', '\r']).len();
+            if end > start {
+                let range = super::compat::span_to_range(file, &(start..end));
+                tokens.push(SemanticToken {
+                    delta_line: range.start.line - prev.line,
+                    delta_start: if range.start.line == prev.line {
+                        range.start.character - prev.character
+                    } else {
+                        range.start.character
+                    },
+                    length: range.end.character - range.start.character,
+                    token_type: token_type(span.category),
+                    token_modifiers_bitset: 0,
+                });
+                prev = range.start;
+            }
+            start += line.len();
+        }
+    }
+    tokens
+}
@@ -1,5 +1,6 @@
 #![forbid(unsafe_code)]
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use codespan_reporting::diagnostic::Severity;
@@ -9,6 +10,7 @@ use codespan_reporting::term::{self, DisplayStyle};
 use logos::Logos;
 
 use backend::rust::RustOutput;
+use frontend::diag::apply_severity_overrides;
 use frontend::parser::*;
 use frontend::printer::DebugPrinter;
 use frontend::sema::*;
@@ -29,6 +31,7 @@ pub fn build(path: &str) {
         0,
         false,
         false,
+        &HashMap::new(),
     );
     match res {
         Err(err) => {
@@ -49,6 +52,7 @@ pub fn compile(
     verbose: u8,
     graph: bool,
     short: bool,
+    overrides: &HashMap<&str, Option<Severity>>,
 ) -> std::io::Result<bool> {
     let input_path = Path::new(input);
     input_path.try_exists()?;
@@ -58,6 +62,7 @@ pub fn compile(
     let (tokens, ranges) = tokenize(Token::lexer(&source), &mut diags);
     let cst = Parser::parse(&source, tokens, ranges, &mut diags);
     let sema = SemanticPass::run(&cst, &mut diags);
+    apply_severity_overrides(&mut diags, overrides);
 
     if verbose > 1 {
         println!("{cst}");
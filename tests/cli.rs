@@ -0,0 +1,29 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+#[test]
+fn severity_overrides() {
+    let output = Command::new(env!("CARGO_BIN_EXE_llw"))
+        .args(["-c", "-s", "-W", "W001=error", "-W", "W002=allow"])
+        .arg("tests/frontend/unused_element.llw")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr.matches("error[W001]").count(), 3);
+    assert!(!stderr.contains("W002"), "{stderr}");
+}
+
+#[test]
+fn invalid_severity_override() {
+    let output = Command::new(env!("CARGO_BIN_EXE_llw"))
+        .args(["-c", "-W", "W001"])
+        .arg("tests/frontend/unused_element.llw")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+}
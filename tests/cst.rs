@@ -0,0 +1,154 @@
+use lelwel::frontend::parser::{tokenize, Cst, Node, NodeRef, Parser, Rule, Token, Visitor};
+use logos::Logos;
+
+fn find_token(cst: &Cst, node: NodeRef, offset: usize) -> Option<NodeRef> {
+    match cst.get(node) {
+        Node::Token(_) => cst
+            .get_span(node)
+            .filter(|span| span.start == offset)
+            .map(|_| node),
+        Node::Rule(..) => cst
+            .children(node)
+            .find_map(|child| find_token(cst, child, offset)),
+    }
+}
+
+fn parse(source: &str) -> Cst<'_> {
+    let mut diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut diags);
+    let cst = Parser::parse(source, tokens, ranges, &mut diags);
+    assert!(diags.is_empty());
+    cst
+}
+
+#[test]
+fn children_of_kind() {
+    let cst = parse("token A B;\nstart s;\ns: A;\nt: B;\n");
+
+    assert_eq!(cst.child_rules(NodeRef::ROOT, Rule::RuleDecl).count(), 2);
+    assert!(cst.child_rule(NodeRef::ROOT, Rule::RightDecl).is_none());
+
+    let token_list = cst.child_rule(NodeRef::ROOT, Rule::TokenList).unwrap();
+    assert_eq!(cst.child_rules(token_list, Rule::TokenDecl).count(), 2);
+    assert_eq!(
+        cst.child_token(token_list, Token::Token),
+        Some(("token", 0..5))
+    );
+    assert_eq!(cst.child_token(token_list, Token::Id), None);
+
+    let start_decl = cst.child_rule(NodeRef::ROOT, Rule::StartDecl).unwrap();
+    assert_eq!(
+        cst.child_tokens(start_decl, Token::Id).collect::<Vec<_>>(),
+        vec![("s", 17..18)]
+    );
+}
+
+#[test]
+fn leading_and_trailing_trivia() {
+    let source = "token A; // a\n// b\nstart s;\ns: A;\n";
+    let cst = parse(source);
+
+    let semi = find_token(&cst, NodeRef::ROOT, 7).unwrap();
+    assert_eq!(
+        cst.trailing_trivia(semi),
+        vec![(Token::Whitespace, 8..9), (Token::Comment, 9..14)]
+    );
+    assert_eq!(cst.leading_trivia(semi), vec![]);
+
+    let start = find_token(&cst, NodeRef::ROOT, 19).unwrap();
+    assert_eq!(
+        cst.trailing_trivia(start),
+        vec![(Token::Whitespace, 24..25)]
+    );
+    assert_eq!(cst.leading_trivia(start), vec![(Token::Comment, 14..19)]);
+}
+
+#[test]
+fn block_comment_trivia() {
+    let source = "token A; /* a\nb */\n/* c\nd */ start s;\ns: A;\n";
+    let cst = parse(source);
+
+    let semi = find_token(&cst, NodeRef::ROOT, 7).unwrap();
+    assert_eq!(
+        cst.trailing_trivia(semi),
+        vec![(Token::Whitespace, 8..9), (Token::Comment, 9..18)]
+    );
+    let start = find_token(&cst, NodeRef::ROOT, 29).unwrap();
+    assert_eq!(
+        cst.leading_trivia(start),
+        vec![
+            (Token::Whitespace, 18..19),
+            (Token::Comment, 19..28),
+            (Token::Whitespace, 28..29)
+        ]
+    );
+}
+
+#[test]
+fn node_and_token_at_offset() {
+    let source = "token A;\nstart s;\ns: A;\n";
+    let cst = parse(source);
+
+    let token = cst.token_at(19).unwrap();
+    assert_eq!(cst.get_token(token, Token::Colon), Some((":", 19..20)));
+    let token = cst.token_at(18).unwrap();
+    assert_eq!(cst.get_token(token, Token::Id), Some(("s", 18..19)));
+    let token = cst.token_at(8).unwrap();
+    assert_eq!(cst.get_token(token, Token::Whitespace), Some(("\n", 8..9)));
+    assert_eq!(cst.token_at(source.len()), None);
+
+    let node = cst.node_at(18).unwrap();
+    assert!(cst.get_rule(node, Rule::RuleDecl).is_some());
+    assert_eq!(cst.get_span(node), Some(18..23));
+    // the newline is a child of the token list, but no span other than the root contains it
+    let token_list = cst.child_rule(NodeRef::ROOT, Rule::TokenList);
+    assert_eq!(cst.parent(cst.token_at(8).unwrap()), token_list);
+    assert_eq!(cst.node_at(8), Some(NodeRef::ROOT));
+    assert_eq!(cst.node_at(source.len()), None);
+}
+
+#[test]
+fn visitor() {
+    #[derive(Default)]
+    struct Collector {
+        ids: Vec<String>,
+        rules: usize,
+    }
+    impl Visitor for Collector {
+        fn token(&mut self, cst: &Cst, node: NodeRef, _token: Token) {
+            if let Some((id, _)) = cst.get_token(node, Token::Id) {
+                self.ids.push(id.to_string());
+            }
+        }
+        fn visit_rule_decl(&mut self, cst: &Cst, node: NodeRef) {
+            self.rules += 1;
+            self.walk(cst, node);
+        }
+        fn visit_start_decl(&mut self, _cst: &Cst, _node: NodeRef) {}
+    }
+    let cst = parse("token A B;\nstart s;\ns: A t;\nt: B;\n");
+    let mut collector = Collector::default();
+    collector.visit(&cst, NodeRef::ROOT);
+
+    assert_eq!(collector.ids, ["A", "B", "s", "A", "t", "t", "B"]);
+    assert_eq!(collector.rules, 2);
+}
+
+#[test]
+fn parent_and_ancestors() {
+    let cst = parse("token A;\nstart s;\ns: (A);\n");
+    let token = cst.token_at(22).unwrap();
+    assert!(cst.get_token(token, Token::Id).is_some());
+
+    let atomic = cst.parent(token).unwrap();
+    assert!(cst.get_rule(atomic, Rule::Atomic).is_some());
+    let paren = cst.parent(atomic).unwrap();
+    assert!(cst.get_rule(paren, Rule::Paren).is_some());
+    let rule_decl = cst
+        .ancestors(token)
+        .find(|node| cst.get_rule(*node, Rule::RuleDecl).is_some())
+        .unwrap();
+    assert_eq!(cst.get_span(rule_decl), Some(18..25));
+    assert_eq!(cst.ancestors(token).last(), Some(NodeRef::ROOT));
+    assert_eq!(cst.parent(NodeRef::ROOT), None);
+}
@@ -0,0 +1,173 @@
+use codespan_reporting::diagnostic::{Label, LabelStyle, Severity};
+use lelwel::frontend::diag::{
+    apply_severity_overrides, merge_diagnostics, render_diagnostic_plain, REDEFINITION,
+    UNUSED_RULE, UNUSED_TOKEN,
+};
+use lelwel::frontend::parser::{tokenize, Diagnostic, Parser, Token};
+use lelwel::frontend::sema::SemanticPass;
+use logos::Logos;
+use std::collections::HashMap;
+
+fn gen_diags(source: &str) -> Vec<Diagnostic> {
+    let mut diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut diags);
+    let cst = Parser::parse(source, tokens, ranges, &mut diags);
+    let _ = SemanticPass::run(&cst, &mut diags);
+    diags
+}
+
+fn render_diags(input: &str) -> String {
+    let source = std::fs::read_to_string(input).unwrap();
+    gen_diags(&source)
+        .iter()
+        .map(|diag| render_diagnostic_plain(input, &source, diag))
+        .collect()
+}
+
+#[test]
+fn plain_undefined() {
+    assert_eq!(
+        render_diags("tests/frontend/undefined.llw"),
+        "tests/frontend/undefined.llw:4:3: error[E004]: use of undefined token `A`\n  \
+         A b C d\n  \
+         ^\n\
+         tests/frontend/undefined.llw:4:5: error[E003]: use of undefined rule `b`\n  \
+         A b C d\n    \
+         ^\n\
+         tests/frontend/undefined.llw:4:7: error[E004]: use of undefined token `C`\n  \
+         A b C d\n      \
+         ^\n\
+         tests/frontend/undefined.llw:4:9: error[E003]: use of undefined rule `d`\n  \
+         A b C d\n        \
+         ^\n"
+    );
+}
+
+#[test]
+fn plain_without_label() {
+    assert_eq!(
+        render_diags("tests/frontend/empty.llw"),
+        "error[E008]: missing start rule\n\
+         = help: specify the start rule with\n\nstart rule_name;\n"
+    );
+}
+
+#[test]
+fn merge_lexer_and_parser_diagnostics() {
+    let source = "start s;\ns: ; ;\n$ r: A;\n";
+    let mut lexer_diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut lexer_diags);
+    let mut parser_diags = vec![];
+    let _ = Parser::parse(source, tokens, ranges, &mut parser_diags);
+    let mut duplicate = parser_diags.clone();
+    duplicate.extend(lexer_diags.clone());
+    duplicate.push(Diagnostic::error().with_message("missing"));
+
+    let merged = merge_diagnostics(lexer_diags, parser_diags);
+    let merged = merge_diagnostics(merged, duplicate);
+    let rendered = merged
+        .iter()
+        .map(|diag| render_diagnostic_plain("input", source, diag))
+        .collect::<String>();
+    assert_eq!(
+        rendered,
+        "input:2:6: error: invalid syntax, expected one of: <end of file>, <identifier>, \
+         'right', 'skip', 'start', 'token'\n\
+         s: ; ;\n     \
+         ^\n\
+         input:3:1: error: invalid token\n\
+         $ r: A;\n\
+         ^\n\
+         error: missing\n"
+    );
+}
+
+#[test]
+fn severity_overrides() {
+    let mut diags =
+        gen_diags(&std::fs::read_to_string("tests/frontend/unused_element.llw").unwrap());
+    let overrides = [(UNUSED_RULE, Some(Severity::Error)), (UNUSED_TOKEN, None)].into();
+    apply_severity_overrides(&mut diags, &overrides);
+
+    assert_eq!(diags.len(), 3);
+    assert!(diags
+        .iter()
+        .all(|diag| diag.severity == Severity::Error && diag.code.as_deref() == Some(UNUSED_RULE)));
+}
+
+#[test]
+fn compile_with_severity_overrides() {
+    let input = "tests/frontend/unused_element.llw";
+    let compile = |overrides| lelwel::compile(input, ".", true, 0, false, true, &overrides);
+
+    assert!(compile(HashMap::new()).unwrap());
+    assert!(!compile([(UNUSED_RULE, Some(Severity::Error))].into()).unwrap());
+    assert!(compile([(UNUSED_RULE, Some(Severity::Note)), (UNUSED_TOKEN, None)].into()).unwrap());
+}
+
+#[test]
+fn redefinition_labels() {
+    let diags = gen_diags("token A;\nstart s;\ns: A;\ns: A;\n");
+    let diag = diags
+        .iter()
+        .find(|diag| diag.code.as_deref() == Some(REDEFINITION))
+        .unwrap();
+    let labels = diag
+        .labels
+        .iter()
+        .map(|label| (label.style, label.range.clone(), label.message.as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        labels,
+        [
+            (LabelStyle::Primary, 24..29, ""),
+            (LabelStyle::Secondary, 18..23, "previous definition"),
+        ]
+    );
+}
+
+#[test]
+fn plain_redefinition() {
+    assert_eq!(
+        render_diags("tests/frontend/redefinition.llw"),
+        "tests/frontend/redefinition.llw:3:6: error: invalid syntax, expected: <identifier>\n\
+         start:\n     \
+         ^\n\
+         tests/frontend/redefinition.llw:9:1: error[E005]: redefinition of rule\n\
+         a:\n\
+         ^^\n\
+         tests/frontend/redefinition.llw:6:1: previous definition\n\
+         tests/frontend/redefinition.llw:14:1: error[E005]: redefinition of rule\n\
+         b:\n\
+         ^^\n\
+         tests/frontend/redefinition.llw:12:1: previous definition\n\
+         tests/frontend/redefinition.llw:1:9: error[E005]: redefinition of token\n\
+         token A A B B;\n        \
+         ^\n\
+         tests/frontend/redefinition.llw:1:7: previous definition\n\
+         tests/frontend/redefinition.llw:1:13: error[E005]: redefinition of token\n\
+         token A A B B;\n            \
+         ^\n\
+         tests/frontend/redefinition.llw:1:11: previous definition\n\
+         error[E008]: missing start rule\n\
+         = help: specify the start rule with\n\nstart rule_name;\n"
+    );
+}
+
+#[test]
+fn plain_char_boundary() {
+    let diag = Diagnostic::error()
+        .with_message("invalid label")
+        .with_labels(vec![
+            Label::primary((), 5..6).with_message("inside"),
+            Label::secondary((), 1..100).with_message("out of bounds"),
+        ]);
+    assert_eq!(
+        render_diagnostic_plain("input", "a\n\u{e9}\u{e9}", &diag),
+        "input:2:2: error: invalid label\n\
+         \u{e9}\u{e9}\n \
+         ^ inside\n\
+         input:1:2: out of bounds\n"
+    );
+}
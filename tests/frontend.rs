@@ -146,6 +146,30 @@ fn lua() {
     assert_eq!(lines.next(), None);
 }
 
+#[test]
+#[rustfmt::skip]
+fn non_productive() {
+    let diags = gen_diags("tests/frontend/non_productive.llw");
+    let mut lines = diags.lines();
+
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive.llw:13:1: warning[W001]: unused rule");
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive.llw:17:1: warning[W001]: unused rule");
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive.llw:9:1: warning[W003]: non-productive rule");
+    assert_eq!(lines.next(), None);
+}
+
+#[test]
+#[rustfmt::skip]
+fn non_productive_cycle() {
+    let diags = gen_diags("tests/frontend/non_productive_cycle.llw");
+    let mut lines = diags.lines();
+
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive_cycle.llw:3:6: error[E015]: no tokens consumed");
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive_cycle.llw:4:4: error[E015]: no tokens consumed");
+    assert_eq!(lines.next().unwrap(), "tests/frontend/non_productive_cycle.llw:5:4: error[E015]: no tokens consumed");
+    assert_eq!(lines.next(), None);
+}
+
 #[test]
 #[rustfmt::skip]
 fn oberon0() {
@@ -0,0 +1,92 @@
+use lelwel::frontend::highlight::{
+    highlight, highlight_cst, highlight_tokens, Highlight, HighlightCategory, HighlightSpan,
+};
+use lelwel::frontend::parser::{tokenize, Parser, Token};
+use logos::Logos;
+
+fn span(byte_range: std::ops::Range<usize>, category: HighlightCategory) -> HighlightSpan {
+    HighlightSpan {
+        byte_range,
+        category,
+    }
+}
+
+#[test]
+fn highlight_grammar() {
+    use HighlightCategory::*;
+    let source = "// comment\ntoken A='a';\nstart s;\ns: A #1 @x;\n";
+    assert_eq!(
+        highlight(source),
+        vec![
+            span(0..11, Comment),
+            span(11..16, Keyword),
+            span(18..19, Operator),
+            span(19..22, String),
+            span(22..23, Operator),
+            span(24..29, Keyword),
+            span(31..32, Operator),
+            span(34..35, Operator),
+            span(38..40, Macro),
+            span(41..43, Macro),
+            span(43..44, Operator),
+        ]
+    );
+}
+
+#[test]
+fn highlight_parsed_grammar() {
+    let source = "/* a\nb */ token A='a';\nstart s;\ns: A | ;\n";
+    let mut diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut diags);
+    let cst = Parser::parse(source, tokens, ranges, &mut diags);
+    assert_eq!(highlight_cst(&cst), highlight(source));
+}
+
+#[derive(logos::Logos)]
+#[logos(skip r"[ \t\n]+")]
+enum Pl0Token {
+    #[token("const")]
+    Const,
+    #[regex("[a-z]+")]
+    Ident,
+    #[regex("[0-9]+")]
+    Number,
+    #[token("=")]
+    Equal,
+    #[token(";")]
+    Semi,
+    #[regex(r"\{[^}]*\}")]
+    Comment,
+}
+
+impl Highlight for Pl0Token {
+    fn category(&self) -> Option<HighlightCategory> {
+        match self {
+            Pl0Token::Const => Some(HighlightCategory::Keyword),
+            Pl0Token::Number => Some(HighlightCategory::Number),
+            Pl0Token::Equal | Pl0Token::Semi => Some(HighlightCategory::Operator),
+            Pl0Token::Comment => Some(HighlightCategory::Comment),
+            Pl0Token::Ident => None,
+        }
+    }
+}
+
+#[test]
+fn highlight_custom_tokens() {
+    use HighlightCategory::*;
+    let source = "{ answer }\nconst x = 42;\n";
+    let (tokens, ranges): (Vec<_>, Vec<_>) = Pl0Token::lexer(source)
+        .spanned()
+        .map(|(token, range)| (token.unwrap(), range))
+        .unzip();
+    assert_eq!(
+        highlight_tokens(&tokens, &ranges),
+        vec![
+            span(0..10, Comment),
+            span(11..16, Keyword),
+            span(19..20, Operator),
+            span(21..23, Number),
+            span(23..24, Operator),
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "lsp")]
+
+use codespan_reporting::files::SimpleFile;
+use lelwel::frontend::parser::{tokenize, Parser, Token};
+use lelwel::ide::semantic_tokens;
+use logos::Logos;
+
+const KEYWORD: u32 = 0;
+const STRING: u32 = 1;
+const COMMENT: u32 = 3;
+const OPERATOR: u32 = 4;
+
+#[test]
+fn delta_encoded_tokens() {
+    let source = "token A='\u{fc}' B;\n/* a\nbc */ start s;\ns: A B;\n";
+    let mut diags = vec![];
+    let (tokens, ranges) = tokenize(Token::lexer(source), &mut diags);
+    let cst = Parser::parse(source, tokens, ranges, &mut diags);
+    let file = SimpleFile::new("test.llw", source);
+
+    let tokens = semantic_tokens(&file, &cst)
+        .into_iter()
+        .map(|token| {
+            (
+                token.delta_line,
+                token.delta_start,
+                token.length,
+                token.token_type,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        [
+            (0, 0, 5, KEYWORD),
+            (0, 7, 1, OPERATOR),
+            // the string is three UTF-16 code units long, but four bytes
+            (0, 1, 3, STRING),
+            (0, 5, 1, OPERATOR),
+            // the block comment is split into one token per line
+            (1, 0, 4, COMMENT),
+            (1, 0, 5, COMMENT),
+            (0, 6, 5, KEYWORD),
+            (0, 7, 1, OPERATOR),
+            (1, 1, 1, OPERATOR),
+            (0, 5, 1, OPERATOR),
+        ]
+    );
+}
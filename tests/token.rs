@@ -0,0 +1,25 @@
+use lelwel::frontend::parser::Token;
+
+#[test]
+fn all_tokens() {
+    let all = Token::all();
+    assert_eq!(all.len(), 25);
+    assert_eq!(all[0], Token::EOF);
+    assert!(!all.contains(&Token::Error));
+}
+
+#[test]
+fn token_name() {
+    assert_eq!(Token::EOF.name(), "EOF");
+    assert_eq!(Token::LPar.name(), "LPar");
+    assert_eq!(Token::Error.name(), "Error");
+}
+
+#[test]
+fn token_spelling() {
+    assert_eq!(Token::Start.spelling(), Some("start"));
+    assert_eq!(Token::LPar.spelling(), Some("("));
+    assert_eq!(Token::Id.spelling(), None);
+    assert_eq!(Token::Whitespace.spelling(), None);
+    assert_eq!(Token::EOF.spelling(), None);
+}